@@ -67,32 +67,39 @@ impl<V> HopcroftKarp<V> where V: Hash + Copy + Eq {
         HopcroftKarp::<V>{ pair_left, pair_right, distance, size:0, max_size }
     }
 
-    fn compute(mut self, graph:&BGraph<V>) -> Vec<Edge<V>> {
-        while self.bfs(&graph) && self.size < self.max_size {
+    // Runs phases of Hopcroft-Karp (a BFS layering pass followed by a DFS
+    // pass that augments along every vertex-disjoint shortest path found)
+    // until no more augmenting paths exist. Shared by `compute`,
+    // `compute_size` and `run`, which only differ in what they do with
+    // the solved `pair_left`/`pair_right` maps afterwards.
+    fn run_phases(&mut self, graph: &BGraph<V>) {
+        while self.bfs(graph) && self.size < self.max_size {
             for u in &graph.left {
-                if self.pair_left[u] == Guarded::GUARD {
-                    if self.dfs(&Guarded::VALUE(*u), &graph) {
-                        self.size += 1;
-                    }
+                if self.pair_left[u] == Guarded::GUARD && self.dfs(&Guarded::VALUE(*u), graph) {
+                    self.size += 1;
                 }
             }
         }
+    }
+
+    fn compute(mut self, graph:&BGraph<V>) -> Vec<Edge<V>> {
+        self.run_phases(graph);
         self.pair_left.into_iter().filter(|(_,v)| v != &Guarded::GUARD ).map(|(u,v)| (u, *v.vertex())).collect()
     }
 
     fn compute_size(mut self, graph:&BGraph<V>) -> usize {
-        while self.bfs(&graph) && self.size < self.max_size {
-            for u in &graph.left {
-                if self.pair_left[u] == Guarded::GUARD {
-                    if self.dfs(&Guarded::VALUE(*u), &graph) {
-                        self.size += 1;
-                    }
-                }
-            }
-        }
+        self.run_phases(graph);
         self.size
     }
 
+    // Like `compute`/`compute_size`, but keeps the solved `pair_left`/
+    // `pair_right` maps around instead of converting them, so callers like
+    // the König's-theorem vertex cover can inspect the matching directly.
+    fn run(mut self, graph:&BGraph<V>) -> Self {
+        self.run_phases(graph);
+        self
+    }
+
     fn bfs(&mut self, graph:&BGraph<V>) -> bool {
         let mut queue:VecDeque<Guarded<V>> = VecDeque::default();
         
@@ -256,7 +263,184 @@ impl<V> BGraph<V> where V: Hash + Copy + Eq {
             Guarded::GUARD => panic!(),
             Guarded::VALUE(u) => self.adj[u].iter(),
         }
-    }    
+    }
+
+    fn from_input<G, P>(input: &G, is_left: P) -> BGraph<V>
+    where G: BipartiteInput<V>, P: Fn(&V) -> bool {
+        let mut left = FxHashSet::default();
+        let mut right = FxHashSet::default();
+        let mut adj: FxHashMap<V, VertexSet<V>> = FxHashMap::default();
+
+        for u in input.vertices() {
+            if is_left(&u) {
+                left.insert(u);
+            } else {
+                right.insert(u);
+            }
+        }
+
+        for u in left.iter().chain(right.iter()) {
+            adj.entry(*u).or_default();
+            for v in input.neighbours(u) {
+                let same_side = (left.contains(u) && left.contains(&v))
+                    || (right.contains(u) && right.contains(&v));
+                if same_side {
+                    panic!("Provided graph is not bipartite!");
+                }
+                adj.entry(*u).or_default().insert(v);
+                adj.entry(v).or_default().insert(*u);
+            }
+        }
+
+        BGraph { left, right, adj }
+    }
+
+    // Like `compute`/`compute_size`, but take the graph by reference so a
+    // [`BipartiteGraph`] builder can be matched repeatedly without handing
+    // over (and having to rebuild) its adjacency maps each time.
+    fn compute_by_ref(&self) -> Vec<Edge<V>> {
+        HopcroftKarp::new(self).compute(self)
+    }
+
+    fn compute_size_by_ref(&self) -> usize {
+        HopcroftKarp::new(self).compute_size(self)
+    }
+}
+
+/// A retained, incremental bipartite graph builder, wrapping the adjacency
+/// representation the matching algorithms use internally. Unlike the plain
+/// `Vec<(V, V)>` entry points, which re-parse the whole edge list on every
+/// call, a `BipartiteGraph` can be built up one node/edge at a time, keeps
+/// isolated vertices on either side, and can be matched repeatedly without
+/// rebuilding its adjacency maps.
+pub struct BipartiteGraph<V> where V: Hash + Copy + Eq {
+    graph: BGraph<V>,
+}
+
+impl<V> BipartiteGraph<V> where V: Hash + Copy + Eq {
+    pub fn new() -> Self {
+        BipartiteGraph {
+            graph: BGraph { left: FxHashSet::default(), right: FxHashSet::default(), adj: FxHashMap::default() },
+        }
+    }
+
+    /// Adds `u` to the left side. Returns whether it was new. Isolated
+    /// vertices (with no incident edges) are allowed.
+    pub fn add_node_left(&mut self, u: V) -> bool {
+        if self.graph.right.contains(&u) {
+            panic!("Provided graph is not bipartite!");
+        }
+        self.graph.adj.entry(u).or_default();
+        self.graph.left.insert(u)
+    }
+
+    /// Adds `v` to the right side. Returns whether it was new.
+    pub fn add_node_right(&mut self, v: V) -> bool {
+        if self.graph.left.contains(&v) {
+            panic!("Provided graph is not bipartite!");
+        }
+        self.graph.adj.entry(v).or_default();
+        self.graph.right.insert(v)
+    }
+
+    /// Adds the edge `(u, v)`, adding `u`/`v` to the left/right side if
+    /// they are not already present. Returns whether the edge was new;
+    /// adding the same edge twice collapses into one.
+    pub fn add_edge(&mut self, u: V, v: V) -> bool {
+        if u == v || self.graph.right.contains(&u) || self.graph.left.contains(&v) {
+            panic!("Provided graph is not bipartite!");
+        }
+        self.graph.left.insert(u);
+        self.graph.right.insert(v);
+        let is_new = self.graph.adj.entry(u).or_default().insert(v);
+        self.graph.adj.entry(v).or_default().insert(u);
+        is_new
+    }
+
+    pub fn contains_edge(&self, u: &V, v: &V) -> bool {
+        match self.graph.adj.get(u) {
+            Some(nbrs) => nbrs.contains(v),
+            None => false,
+        }
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.graph.left.len() + self.graph.right.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.graph.adj.values().map(|nbrs| nbrs.len()).sum::<usize>() / 2
+    }
+
+    pub fn matching(&self) -> Vec<Edge<V>> {
+        self.graph.compute_by_ref()
+    }
+
+    pub fn matching_size(&self) -> usize {
+        self.graph.compute_size_by_ref()
+    }
+}
+
+impl<V> Default for BipartiteGraph<V> where V: Hash + Copy + Eq {
+    fn default() -> Self {
+        BipartiteGraph::new()
+    }
+}
+
+/// A source of bipartite edges that the matching algorithms can consume
+/// directly, so callers who already hold a graph structure don't have to
+/// flatten it into a `Vec<(V, V)>` first.
+pub trait BipartiteInput<V> where V: Hash + Copy + Eq {
+    /// All vertices appearing in the graph, on either side of the bipartition.
+    fn vertices(&self) -> Vec<V>;
+    /// The neighbours of `u`.
+    fn neighbours(&self, u: &V) -> Vec<V>;
+}
+
+impl<V> BipartiteInput<V> for Vec<Edge<V>> where V: Hash + Copy + Eq {
+    fn vertices(&self) -> Vec<V> {
+        let mut seen: VertexSet<V> = FxHashSet::default();
+        let mut out = Vec::new();
+        for &(u, v) in self {
+            if seen.insert(u) { out.push(u); }
+            if seen.insert(v) { out.push(v); }
+        }
+        out
+    }
+
+    fn neighbours(&self, u: &V) -> Vec<V> {
+        self.iter()
+            .filter_map(|&(a, b)| if a == *u { Some(b) } else if b == *u { Some(a) } else { None })
+            .collect()
+    }
+}
+
+#[cfg(feature = "petgraph")]
+impl<V, W> BipartiteInput<V> for petgraph::graphmap::UnGraphMap<V, W>
+where V: Hash + Copy + Eq + petgraph::graphmap::NodeTrait {
+    fn vertices(&self) -> Vec<V> {
+        self.nodes().collect()
+    }
+
+    fn neighbours(&self, u: &V) -> Vec<V> {
+        self.neighbors(*u).collect()
+    }
+}
+
+/// Computes a maximum matching over any graph implementing
+/// [`BipartiteInput`] — e.g. a `petgraph` `UnGraphMap` with the `petgraph`
+/// feature enabled — given a predicate that decides which vertices sit on
+/// the left of the bipartition. Panics if an edge connects two vertices on
+/// the same side.
+pub fn matching_from_graph<V, G, P>(graph: &G, is_left: P) -> Vec<Edge<V>>
+where V: Hash + Copy + Eq, G: BipartiteInput<V>, P: Fn(&V) -> bool {
+    BGraph::from_input(graph, is_left).compute()
+}
+
+/// Like [`matching_from_graph`], but only returns the size of the matching.
+pub fn matching_from_graph_size<V, G, P>(graph: &G, is_left: P) -> usize
+where V: Hash + Copy + Eq, G: BipartiteInput<V>, P: Fn(&V) -> bool {
+    BGraph::from_input(graph, is_left).compute_size()
 }
 
 pub fn matching<V>(edges:&Vec<Edge<V>>) -> Vec<Edge<V>> where V: Hash + Copy + Eq {
@@ -295,6 +479,492 @@ pub fn bounded_matching_mapped_size<V>(edges:&Vec<Edge<V>>, bound:usize) -> usiz
     graph.compute_bounded_size(bound)
 }
 
+// ---------------------------------------------------------------------------
+// Vertex cover / independent set, via König's theorem
+//
+// After computing a maximum matching, an alternating search from the
+// unmatched left vertices (following unmatched edges left->right and
+// matched edges right->left) reaches a vertex set `Z`. König's theorem
+// says `(left \ Z) ∪ (right ∩ Z)` is a minimum vertex cover, and its
+// complement is a maximum independent set.
+// ---------------------------------------------------------------------------
+
+fn konig<V>(graph: &BGraph<V>) -> (Vec<V>, Vec<V>) where V: Hash + Copy + Eq {
+    let hk = HopcroftKarp::new(graph).run(graph);
+
+    let mut z_left: VertexSet<V> = FxHashSet::default();
+    let mut z_right: VertexSet<V> = FxHashSet::default();
+    let mut queue: VecDeque<V> = VecDeque::default();
+
+    for &u in &graph.left {
+        if hk.pair_left[&u] == Guarded::GUARD {
+            z_left.insert(u);
+            queue.push_back(u);
+        }
+    }
+
+    while let Some(u) = queue.pop_front() {
+        for &v in graph.neighbours(&u) {
+            if hk.pair_left[&u] == Guarded::VALUE(v) {
+                continue; // only follow unmatched edges away from a left vertex
+            }
+            if z_right.insert(v) {
+                if let Guarded::VALUE(u2) = hk.pair_right[&v] {
+                    if z_left.insert(u2) {
+                        queue.push_back(u2);
+                    }
+                }
+            }
+        }
+    }
+
+    let cover: Vec<V> = graph.left.iter().filter(|u| !z_left.contains(u)).copied()
+        .chain(graph.right.iter().filter(|v| z_right.contains(v)).copied())
+        .collect();
+    let independent_set: Vec<V> = graph.left.iter().filter(|u| z_left.contains(u)).copied()
+        .chain(graph.right.iter().filter(|v| !z_right.contains(v)).copied())
+        .collect();
+
+    (cover, independent_set)
+}
+
+/// Computes a minimum vertex cover of the bipartite graph given by `edges`,
+/// via König's theorem.
+pub fn min_vertex_cover<V>(edges:&Vec<Edge<V>>) -> Vec<V> where V: Hash + Copy + Eq {
+    konig(&BGraph::new(edges)).0
+}
+
+/// Computes a maximum independent set of the bipartite graph given by
+/// `edges`, via König's theorem.
+pub fn max_independent_set<V>(edges:&Vec<Edge<V>>) -> Vec<V> where V: Hash + Copy + Eq {
+    konig(&BGraph::new(edges)).1
+}
+
+/// Like [`min_vertex_cover`], but maps vertices to `usize` internally
+/// first, mirroring [`matching_mapped`].
+pub fn min_vertex_cover_mapped<V>(edges:&Vec<Edge<V>>) -> Vec<V> where V: Hash + Copy + Eq {
+    let (graph, mapping) = BGraph::new_mapped(edges);
+    konig(&graph).0.iter().map(|u| mapping[u]).collect()
+}
+
+/// Like [`max_independent_set`], but maps vertices to `usize` internally
+/// first, mirroring [`matching_mapped`].
+pub fn max_independent_set_mapped<V>(edges:&Vec<Edge<V>>) -> Vec<V> where V: Hash + Copy + Eq {
+    let (graph, mapping) = BGraph::new_mapped(edges);
+    konig(&graph).1.iter().map(|u| mapping[u]).collect()
+}
+
+// ---------------------------------------------------------------------------
+// Weighted matching (assignment problem)
+//
+// Solves maximum-weight bipartite matching via successive shortest
+// augmenting paths over the residual graph: each round runs a Bellman-Ford
+// search (SPFA) simultaneously from every still-free left vertex, following
+// unmatched left->right edges forward and matched right->left edges
+// backward, and flips the matching along the cheapest path reaching an
+// unmatched right vertex. Bellman-Ford (rather than Dijkstra) is used
+// because the reverse residual edges carry positive cost while forward
+// edges carry negative cost, and a single round must compare paths rooted
+// at *different* free left vertices against each other on equal footing.
+// ---------------------------------------------------------------------------
+
+type WEdge<V, W> = (V, V, W);
+
+struct WBGraph<V, W> {
+    left: VertexSet<V>,
+    right: VertexSet<V>,
+    adj: FxHashMap<V, FxHashMap<V, W>>,
+}
+
+impl<V, W> WBGraph<V, W>
+where
+    V: Hash + Copy + Eq,
+    W: Copy,
+{
+    fn new(edges: &Vec<WEdge<V, W>>) -> WBGraph<V, W> {
+        let mut left = FxHashSet::default();
+        let mut right = FxHashSet::default();
+        let mut adj: FxHashMap<V, FxHashMap<V, W>> = FxHashMap::default();
+        for &(u, v, w) in edges {
+            adj.entry(u).or_default().insert(v, w);
+            adj.entry(v).or_default().insert(u, w);
+            left.insert(u);
+            right.insert(v);
+        }
+
+        if left.intersection(&right).count() > 0 {
+            panic!("Provided graph is not bipartite!");
+        }
+
+        WBGraph { left, right, adj }
+    }
+
+    fn new_mapped(edges: &Vec<WEdge<V, W>>) -> (WBGraph<usize, W>, FxHashMap<usize, V>) {
+        let mut orig_left: FxHashSet<V> = FxHashSet::default();
+        let mut orig_right: FxHashSet<V> = FxHashSet::default();
+
+        for (u, v, _) in edges {
+            orig_left.insert(*u);
+            orig_right.insert(*v);
+        }
+
+        let mut mapping: FxHashMap<V, usize> = FxHashMap::default();
+        let mut back_mapping: FxHashMap<usize, V> = FxHashMap::default();
+        let mut left = FxHashSet::default();
+        let mut right = FxHashSet::default();
+        let mut id = 0;
+        for u in orig_left {
+            mapping.insert(u, id);
+            back_mapping.insert(id, u);
+            left.insert(id);
+            id += 1;
+        }
+        for u in orig_right {
+            mapping.insert(u, id);
+            back_mapping.insert(id, u);
+            right.insert(id);
+            id += 1;
+        }
+
+        let mut adj: FxHashMap<usize, FxHashMap<usize, W>> = FxHashMap::default();
+        for &(u, v, w) in edges {
+            let u_map = *mapping.get(&u).unwrap();
+            let v_map = *mapping.get(&v).unwrap();
+            adj.entry(u_map).or_default().insert(v_map, w);
+            adj.entry(v_map).or_default().insert(u_map, w);
+        }
+
+        (WBGraph { left, right, adj }, back_mapping)
+    }
+}
+
+struct Assignment<V, W>
+where
+    V: Hash + Copy + Eq,
+    W: Copy + Ord + Default + std::ops::Add<Output = W> + std::ops::Neg<Output = W>,
+{
+    pair_left: FxHashMap<V, Guarded<V>>,
+    pair_right: FxHashMap<V, Guarded<V>>,
+    _weight: std::marker::PhantomData<W>,
+}
+
+impl<V, W> Assignment<V, W>
+where
+    V: Hash + Copy + Eq,
+    W: Copy + Ord + Default + std::ops::Add<Output = W> + std::ops::Neg<Output = W>,
+{
+    fn new(graph: &WBGraph<V, W>) -> Self {
+        let pair_left = graph.left.iter().map(|&u| (u, Guarded::GUARD)).collect();
+        let pair_right = graph.right.iter().map(|&v| (v, Guarded::GUARD)).collect();
+        Assignment { pair_left, pair_right, _weight: std::marker::PhantomData }
+    }
+
+    fn compute(mut self, graph: &WBGraph<V, W>, max_size: usize) -> (Vec<Edge<V>>, W) {
+        let mut size = 0;
+        while size < max_size && self.augment(graph) {
+            size += 1;
+        }
+
+        let pairs: Vec<Edge<V>> = self
+            .pair_left
+            .iter()
+            .filter_map(|(&u, pv)| match pv {
+                Guarded::VALUE(v) => Some((u, *v)),
+                Guarded::GUARD => None,
+            })
+            .collect();
+        let mut total = W::default();
+        for &(u, v) in &pairs {
+            total = total + graph.adj[&u][&v];
+        }
+        (pairs, total)
+    }
+
+    // One round of the successive-shortest-augmenting-path method: a
+    // Bellman-Ford search (SPFA) over the residual graph, rooted
+    // simultaneously at every still-free left vertex, following unmatched
+    // left->right edges forward (cost `-w`) and matched right->left edges
+    // backward (cost `w`), until the cheapest reachable unmatched right
+    // vertex is found. Rooting all free left vertices at once — rather than
+    // running one independent search per vertex — is what lets this pick
+    // the globally cheapest augmenting path instead of whichever free left
+    // vertex happened to be tried first.
+    fn augment(&mut self, graph: &WBGraph<V, W>) -> bool {
+        let mut dist: FxHashMap<V, W> = FxHashMap::default();
+        let mut prev: FxHashMap<V, V> = FxHashMap::default();
+        let mut queue: VecDeque<V> = VecDeque::new();
+        let mut queued: FxHashSet<V> = FxHashSet::default();
+
+        for &u in &graph.left {
+            if self.pair_left[&u] == Guarded::GUARD {
+                dist.insert(u, W::default());
+                queue.push_back(u);
+                queued.insert(u);
+            }
+        }
+
+        while let Some(u) = queue.pop_front() {
+            queued.remove(&u);
+            let d = dist[&u];
+            let is_left = graph.left.contains(&u);
+            for (&y, &w) in &graph.adj[&u] {
+                let cost = if is_left {
+                    // `u` is left: every edge but the one it's currently
+                    // matched on has spare forward residual capacity.
+                    if self.pair_left[&u] == Guarded::VALUE(y) {
+                        continue;
+                    }
+                    -w
+                } else {
+                    // `u` is right: the only residual edge out of it is the
+                    // reverse of its current match, if any.
+                    if self.pair_right[&u] != Guarded::VALUE(y) {
+                        continue;
+                    }
+                    w
+                };
+                let nd = d + cost;
+                let improves = match dist.get(&y) {
+                    Some(&cur) => nd < cur,
+                    None => true,
+                };
+                if improves {
+                    dist.insert(y, nd);
+                    prev.insert(y, u);
+                    if !queued.contains(&y) {
+                        queue.push_back(y);
+                        queued.insert(y);
+                    }
+                }
+            }
+        }
+
+        let end = graph
+            .right
+            .iter()
+            .filter(|v| self.pair_right[v] == Guarded::GUARD)
+            .filter_map(|v| dist.get(v).map(|&d| (*v, d)))
+            .min_by_key(|&(_, d)| d)
+            .map(|(v, _)| v);
+        let Some(end) = end else {
+            return false;
+        };
+
+        let mut path = vec![end];
+        let mut cur = end;
+        while let Some(&p) = prev.get(&cur) {
+            path.push(p);
+            cur = p;
+        }
+        path.reverse();
+        for chunk in path.chunks(2) {
+            let (u, v) = (chunk[0], chunk[1]);
+            self.pair_left.insert(u, Guarded::VALUE(v));
+            self.pair_right.insert(v, Guarded::VALUE(u));
+        }
+        true
+    }
+}
+
+/// Solves the maximum-weight bipartite matching (assignment) problem over
+/// `edges`, returning the matched pairs. Like [`matching`], this always
+/// finds a matching of maximum cardinality first; among those, it picks
+/// the one with the greatest total weight. This means a matching that
+/// drops a negative-weight edge can score higher than the one returned
+/// here, since leaving an edge's endpoints unmatched is never considered
+/// if using them grows the matching.
+pub fn weighted_matching<V, W>(edges: &Vec<WEdge<V, W>>) -> Vec<Edge<V>>
+where
+    V: Hash + Copy + Eq,
+    W: Copy + Ord + Default + std::ops::Add<Output = W> + std::ops::Neg<Output = W>,
+{
+    let graph = WBGraph::new(edges);
+    let max_size = std::cmp::min(graph.left.len(), graph.right.len());
+    Assignment::new(&graph).compute(&graph, max_size).0
+}
+
+/// Like [`weighted_matching`], but also returns the total weight of the
+/// matched edges.
+pub fn weighted_matching_weight<V, W>(edges: &Vec<WEdge<V, W>>) -> W
+where
+    V: Hash + Copy + Eq,
+    W: Copy + Ord + Default + std::ops::Add<Output = W> + std::ops::Neg<Output = W>,
+{
+    let graph = WBGraph::new(edges);
+    let max_size = std::cmp::min(graph.left.len(), graph.right.len());
+    Assignment::new(&graph).compute(&graph, max_size).1
+}
+
+/// Like [`weighted_matching`], but maps vertices to `usize` internally
+/// first, mirroring [`matching_mapped`].
+pub fn weighted_matching_mapped<V, W>(edges: &Vec<WEdge<V, W>>) -> Vec<Edge<V>>
+where
+    V: Hash + Copy + Eq,
+    W: Copy + Ord + Default + std::ops::Add<Output = W> + std::ops::Neg<Output = W>,
+{
+    let (graph, mapping) = WBGraph::new_mapped(edges);
+    let max_size = std::cmp::min(graph.left.len(), graph.right.len());
+    let (pairs, _) = Assignment::new(&graph).compute(&graph, max_size);
+    pairs.iter().map(|(u, v)| (mapping[u], mapping[v])).collect()
+}
+
+/// Like [`weighted_matching_weight`], but maps vertices to `usize`
+/// internally first, mirroring [`matching_mapped_size`].
+pub fn weighted_matching_mapped_weight<V, W>(edges: &Vec<WEdge<V, W>>) -> W
+where
+    V: Hash + Copy + Eq,
+    W: Copy + Ord + Default + std::ops::Add<Output = W> + std::ops::Neg<Output = W>,
+{
+    let (graph, _) = WBGraph::new_mapped(edges);
+    let max_size = std::cmp::min(graph.left.len(), graph.right.len());
+    Assignment::new(&graph).compute(&graph, max_size).1
+}
+
+// ---------------------------------------------------------------------------
+// Incremental matching
+// ---------------------------------------------------------------------------
+
+/// An incremental bipartite matching that grows and shrinks in place as
+/// edges are streamed in via [`add_edge`](Matching::add_edge) and
+/// [`remove_edge`](Matching::remove_edge), instead of rebuilding a graph and
+/// rerunning the full Hopcroft-Karp BFS/DFS phases on every change.
+///
+/// The full [`matching`] function is still the right choice for batch
+/// construction; `Matching` is for interactive or streaming use cases.
+pub struct Matching<V> where V: Hash + Copy + Eq {
+    adj: FxHashMap<V, VertexSet<V>>,
+    pair_left: FxHashMap<V, Guarded<V>>,
+    pair_right: FxHashMap<V, Guarded<V>>,
+    size: usize,
+}
+
+impl<V> Matching<V> where V: Hash + Copy + Eq {
+    pub fn new() -> Self {
+        Matching {
+            adj: FxHashMap::default(),
+            pair_left: FxHashMap::default(),
+            pair_right: FxHashMap::default(),
+            size: 0,
+        }
+    }
+
+    /// Adds the edge `(u, v)` and tries to extend the matching along a
+    /// Kuhn-style alternating path. Returns whether the matching grew as a
+    /// result.
+    ///
+    /// The new edge can unlock an augmenting path rooted at *any* currently
+    /// unmatched left vertex, not just `u` — e.g. `u` might already be
+    /// matched, while the new edge to `v` frees up `v`'s old match to reach
+    /// some other free vertex through the rest of the graph. So every free
+    /// left vertex is retried; the matching can grow by at most one per
+    /// edge, so the first successful retry stops the search.
+    pub fn add_edge(&mut self, u: V, v: V) -> bool {
+        if u == v || self.pair_right.contains_key(&u) || self.pair_left.contains_key(&v) {
+            panic!("Provided graph is not bipartite!");
+        }
+
+        self.pair_left.entry(u).or_insert(Guarded::GUARD);
+        self.pair_right.entry(v).or_insert(Guarded::GUARD);
+        let is_new = self.adj.entry(u).or_default().insert(v);
+        self.adj.entry(v).or_default().insert(u);
+        if !is_new {
+            return false;
+        }
+
+        let free_lefts: Vec<V> = self
+            .pair_left
+            .iter()
+            .filter_map(|(&x, pv)| if *pv == Guarded::GUARD { Some(x) } else { None })
+            .collect();
+        for x in free_lefts {
+            let mut visited = FxHashSet::default();
+            if self.try_kuhn(&x, &mut visited) {
+                self.size += 1;
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Removes the edge `(u, v)` if present. If it was part of the current
+    /// matching, tries to repair the matching with a fresh alternating
+    /// search from every free left vertex (including `u`, and any vertex
+    /// that can now reach the freed `v`) before giving up the pair. Returns
+    /// whether the edge was present.
+    pub fn remove_edge(&mut self, u: V, v: V) -> bool {
+        let removed = match self.adj.get_mut(&u) {
+            Some(nbrs) => nbrs.remove(&v),
+            None => false,
+        };
+        if !removed {
+            return false;
+        }
+        self.adj.get_mut(&v).map(|nbrs| nbrs.remove(&u));
+
+        if self.pair_left.get(&u) == Some(&Guarded::VALUE(v)) {
+            self.pair_left.insert(u, Guarded::GUARD);
+            self.pair_right.insert(v, Guarded::GUARD);
+            self.size -= 1;
+
+            let free_lefts: Vec<V> = self
+                .pair_left
+                .iter()
+                .filter_map(|(&x, pv)| if *pv == Guarded::GUARD { Some(x) } else { None })
+                .collect();
+            for x in free_lefts {
+                let mut visited = FxHashSet::default();
+                if self.try_kuhn(&x, &mut visited) {
+                    self.size += 1;
+                    break;
+                }
+            }
+        }
+        true
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn pairs(&self) -> Vec<Edge<V>> {
+        self.pair_left.iter()
+            .filter_map(|(&u, pv)| match pv {
+                Guarded::VALUE(v) => Some((u, *v)),
+                Guarded::GUARD => None,
+            })
+            .collect()
+    }
+
+    // Classic Kuhn augmenting-path search: follow `u`'s neighbours, marking
+    // visited right vertices so each is tried as a reroute target at most
+    // once per search.
+    fn try_kuhn(&mut self, u: &V, visited: &mut VertexSet<V>) -> bool {
+        let neighbours: Vec<V> = self.adj[u].iter().copied().collect();
+        for v in neighbours {
+            if visited.contains(&v) {
+                continue;
+            }
+            visited.insert(v);
+
+            let reroute = match self.pair_right[&v] {
+                Guarded::GUARD => true,
+                Guarded::VALUE(u2) => self.try_kuhn(&u2, visited),
+            };
+            if reroute {
+                self.pair_left.insert(*u, Guarded::VALUE(v));
+                self.pair_right.insert(v, Guarded::VALUE(*u));
+                return true;
+            }
+        }
+        false
+    }
+}
+
+impl<V> Default for Matching<V> where V: Hash + Copy + Eq {
+    fn default() -> Self {
+        Matching::new()
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -398,5 +1068,249 @@ mod tests {
                          ("silk", "doc octopus"), ("silk", "green goblin"),  ("daredevil", "sandman")];
         let res = matching(&edges);
         assert_eq!(res.len(), 3);
-    }    
+    }
+
+    #[test]
+    fn test_weighted_matching() {
+        // Best by cardinality would pair (0,10),(1,11) for 1+2=3, but the
+        // maximum-weight assignment crosses the pairs for 3+4=7.
+        let edges = vec![(0,10,1), (0,11,3), (1,10,4), (1,11,2)];
+        let res = weighted_matching(&edges);
+        assert_eq!(res.len(), 2);
+        assert_eq!(res.iter().copied().collect::<FxHashSet<(i32,i32)>>(),
+                   vec![(0,11), (1,10)].into_iter().collect::<FxHashSet<(i32,i32)>>());
+        assert_eq!(weighted_matching_weight(&edges), 7);
+    }
+
+    #[test]
+    fn test_weighted_matching_mapped() {
+        let edges = vec![("alice", "job1", 5), ("alice", "job2", 1), ("bob", "job1", 2), ("bob", "job2", 6)];
+        let res = weighted_matching_mapped(&edges);
+        assert_eq!(res.iter().copied().collect::<FxHashSet<(&str,&str)>>(),
+                   vec![("alice","job1"), ("bob","job2")].into_iter().collect::<FxHashSet<(&str,&str)>>());
+        assert_eq!(weighted_matching_mapped_weight(&edges), 11);
+    }
+
+    // Brute force over every subset of `edges`, restricted to maximum
+    // cardinality, used to check `weighted_matching_weight` against an
+    // implementation with no shared logic to be wrong in the same way.
+    fn brute_force_weighted_matching(edges: &[(usize, usize, i32)]) -> i32 {
+        let mut best_card = 0;
+        let mut best_weight = i32::MIN;
+        for mask in 0..(1u32 << edges.len()) {
+            let mut used_left = FxHashSet::default();
+            let mut used_right = FxHashSet::default();
+            let mut weight = 0;
+            let mut card = 0;
+            let mut ok = true;
+            for (i, &(u, v, w)) in edges.iter().enumerate() {
+                if mask & (1 << i) != 0 {
+                    if !used_left.insert(u) || !used_right.insert(v) {
+                        ok = false;
+                        break;
+                    }
+                    weight += w;
+                    card += 1;
+                }
+            }
+            if ok && (card > best_card || (card == best_card && weight > best_weight)) {
+                best_card = card;
+                best_weight = weight;
+            }
+        }
+        if best_card == 0 { 0 } else { best_weight }
+    }
+
+    #[test]
+    fn test_weighted_matching_matches_brute_force() {
+        let mut rng = ChaChaRng::from_entropy();
+        for _ in 0..300 {
+            let n_left = 1 + rng.next_u64() as usize % 4;
+            let n_right = 1 + rng.next_u64() as usize % 4;
+            let mut edges = Vec::default();
+            for u in 0..n_left {
+                for v in 0..n_right {
+                    if rng.next_u64() % 2 == 0 {
+                        let w = (rng.next_u64() % 20) as i32 - 10;
+                        edges.push((u, 100 + v, w));
+                    }
+                }
+            }
+            if edges.is_empty() {
+                continue;
+            }
+            let got = weighted_matching_weight(&edges);
+            let want = brute_force_weighted_matching(&edges);
+            assert_eq!(got, want, "edges = {:?}", edges);
+        }
+    }
+
+    #[test]
+    fn test_matching_from_graph() {
+        let edges = vec![(0,10), (0,11), (0,12), (1,11), (2,12)];
+        let res = matching_from_graph(&edges, |u| *u < 10);
+        assert_eq!(res.len(), 3);
+        assert_eq!(res.len(), matching_from_graph_size(&edges, |u| *u < 10));
+    }
+
+    #[test]
+    #[should_panic(expected = "not bipartite")]
+    fn test_matching_from_graph_rejects_right_side_edge() {
+        // Neither endpoint of (10,11) is "left", so a check that only walks
+        // edges out of left vertices never looks at it.
+        let edges = vec![(0,10), (10,11)];
+        matching_from_graph(&edges, |u| *u == 0);
+    }
+
+    #[test]
+    fn test_incremental_matching() {
+        let mut m = Matching::new();
+        assert!(m.add_edge(0, 10));
+        assert_eq!(m.size(), 1);
+
+        // Duplicate edge: no change.
+        assert!(!m.add_edge(0, 10));
+        assert_eq!(m.size(), 1);
+
+        // 1 is unmatched, but 10 is already taken and has no other
+        // neighbour to reroute through, so this does not grow the matching.
+        assert!(!m.add_edge(1, 10));
+        assert_eq!(m.size(), 1);
+        assert!(m.add_edge(1, 11));
+        assert_eq!(m.size(), 2);
+        assert_eq!(m.pairs().iter().copied().collect::<FxHashSet<(i32,i32)>>(),
+                   vec![(0,10), (1,11)].into_iter().collect::<FxHashSet<(i32,i32)>>());
+
+        // Removing (1,11) leaves 1's only other neighbour, 10, already
+        // taken by 0 with nowhere else to go, so the repair search fails
+        // and the matching shrinks back to size 1.
+        assert!(m.remove_edge(1, 11));
+        assert_eq!(m.size(), 1);
+        assert_eq!(m.pairs(), vec![(0, 10)]);
+
+        // Removing an edge that isn't present is a no-op.
+        assert!(!m.remove_edge(2, 20));
+    }
+
+    #[test]
+    #[should_panic(expected = "not bipartite")]
+    fn test_incremental_matching_rejects_self_edge() {
+        let mut m = Matching::new();
+        m.add_edge(7, 7);
+    }
+
+    #[test]
+    fn test_incremental_matching_reroutes_other_free_vertices() {
+        let mut m = Matching::new();
+        assert!(m.add_edge(1, 101));
+        m.add_edge(1, 102); // alternate route for 1, not taken yet
+        assert!(m.add_edge(2, 102));
+        assert!(m.add_edge(5, 105));
+        assert_eq!(m.size(), 3);
+
+        // 0 is still free; this alternating path isn't completable yet
+        // (0->101->1->102->2 dead-ends, since 2 has no edge besides 102),
+        // so it correctly fails.
+        assert!(!m.add_edge(0, 101));
+        assert_eq!(m.size(), 3);
+
+        // Neither of these touches 0 directly — 2 and 5 are already
+        // matched — but together they complete the augmenting path
+        // 0->101->1->102->2->105->5->104, which only a search rooted at
+        // the free vertex 0 (not at 2 or 5) can find.
+        assert!(!m.add_edge(2, 105));
+        assert!(m.add_edge(5, 104));
+        assert_eq!(m.size(), 4);
+        assert_eq!(
+            m.pairs().iter().copied().collect::<FxHashSet<(i32, i32)>>(),
+            vec![(0, 101), (1, 102), (2, 105), (5, 104)].into_iter().collect::<FxHashSet<(i32, i32)>>()
+        );
+    }
+
+    #[test]
+    fn test_incremental_matching_matches_batch() {
+        let mut rng = ChaChaRng::from_entropy();
+        let mut m = Matching::new();
+        let mut edges: Vec<(i32, i32)> = Vec::default();
+        for _ in 0..200 {
+            let u = rng.next_u64() as i32 % 6;
+            let v = 100 + rng.next_u64() as i32 % 6;
+            if rng.next_u64() % 3 == 0 && edges.contains(&(u, v)) {
+                m.remove_edge(u, v);
+                edges.retain(|&e| e != (u, v));
+            } else if !edges.contains(&(u, v)) {
+                m.add_edge(u, v);
+                edges.push((u, v));
+            }
+            assert_eq!(m.size(), matching_size(&edges), "edges = {:?}", edges);
+        }
+    }
+
+    #[test]
+    fn test_vertex_cover() {
+        let edges = vec![(0,10), (0,11), (0,12), (1,11), (2,12)];
+        let cover = min_vertex_cover(&edges);
+        let cover_set: FxHashSet<i32> = cover.iter().copied().collect();
+
+        // A valid cover: every edge has at least one endpoint in it.
+        for &(u, v) in &edges {
+            assert!(cover_set.contains(&u) || cover_set.contains(&v));
+        }
+        // By König's theorem its size matches the maximum matching.
+        assert_eq!(cover.len(), matching_size(&edges));
+
+        let independent = max_independent_set(&edges);
+        let independent_set: FxHashSet<i32> = independent.iter().copied().collect();
+
+        // Independent set and cover partition all vertices...
+        assert_eq!(cover_set.len() + independent_set.len(), 6);
+        assert!(cover_set.is_disjoint(&independent_set));
+        // ...and no edge has both endpoints in the independent set.
+        for &(u, v) in &edges {
+            assert!(!(independent_set.contains(&u) && independent_set.contains(&v)));
+        }
+    }
+
+    #[test]
+    fn test_vertex_cover_mapped() {
+        let edges = vec![("spiderman", "doc octopus"), ("spiderman", "sandman"), ("spiderman", "green goblin"),
+                         ("silk", "doc octopus"), ("silk", "green goblin"),  ("daredevil", "sandman")];
+        let cover = min_vertex_cover_mapped(&edges);
+        assert_eq!(cover.len(), matching_size(&edges));
+
+        let independent = max_independent_set_mapped(&edges);
+        assert_eq!(cover.len() + independent.len(), 6);
+    }
+
+    #[test]
+    fn test_bipartite_graph_builder() {
+        let mut g = BipartiteGraph::new();
+        assert!(g.add_node_left(0));
+        assert!(g.add_node_right(20)); // isolated, no edges
+        assert!(g.add_edge(0, 10));
+        assert!(g.add_edge(0, 11));
+        assert!(g.add_edge(1, 11));
+
+        // Duplicate edge collapses.
+        assert!(!g.add_edge(0, 10));
+
+        assert_eq!(g.node_count(), 5); // 0, 1, 10, 11, 20
+        assert_eq!(g.edge_count(), 3);
+        assert!(g.contains_edge(&0, &10));
+        assert!(!g.contains_edge(&1, &10));
+
+        assert_eq!(g.matching_size(), 2);
+        assert_eq!(g.matching().len(), 2);
+
+        // Further edits are reflected without rebuilding from scratch.
+        assert!(g.add_edge(2, 20));
+        assert_eq!(g.matching_size(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "not bipartite")]
+    fn test_bipartite_graph_builder_rejects_self_edge() {
+        let mut g = BipartiteGraph::new();
+        g.add_edge(5, 5);
+    }
 }